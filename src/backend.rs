@@ -0,0 +1,25 @@
+//! Which tool is used to create environments and install packages.
+
+use xshell::Shell;
+
+/// The tool [`crate::VirtualEnv`] uses to create environments and install packages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// `python -m venv` (or the bundled microvenv) plus `ensurepip`/`pip`.
+    ///
+    /// Used everywhere [`Backend::Uv`] isn't available.
+    Pip,
+    /// [`uv`](https://docs.astral.sh/uv/), used automatically if found on `$PATH`.
+    Uv,
+}
+
+impl Backend {
+    /// Probe `$PATH` for `uv`, falling back to [`Backend::Pip`] if it's not found.
+    pub(crate) fn detect(sh: &Shell) -> Backend {
+        if xshell::cmd!(sh, "uv --version").run().is_ok() {
+            Backend::Uv
+        } else {
+            Backend::Pip
+        }
+    }
+}
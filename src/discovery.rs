@@ -0,0 +1,195 @@
+//! Discovery of a requested Python version from a `.python-version` file.
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+use crate::VersionRequest;
+
+/// Walk up from `CARGO_MANIFEST_DIR` and the current working directory looking for the closest
+/// `.python-version` file, and parse it into a [`VersionRequest`].
+///
+/// Returns `None` if neither starting point has a `.python-version` file above it (before hitting
+/// a search boundary, see [`find_version_file`]), or if the closest one found doesn't contain a
+/// parseable version.
+pub(crate) fn discover() -> Option<VersionRequest> {
+    let mut candidates = Vec::new();
+
+    if let Ok(manifest_dir) = env::var("CARGO_MANIFEST_DIR") {
+        candidates.push(find_version_file(Path::new(&manifest_dir)));
+    }
+    if let Ok(cwd) = env::current_dir() {
+        candidates.push(find_version_file(&cwd));
+    }
+
+    let path = candidates
+        .into_iter()
+        .flatten()
+        .min_by_key(|(_, depth)| *depth)
+        .map(|(path, _)| path)?;
+
+    let contents = std::fs::read_to_string(path).ok()?;
+    parse(&contents)
+}
+
+/// How many directories `find_version_file` will walk up from its starting point, regardless of
+/// `.git`/`$HOME` boundaries. A crate checked out somewhere like
+/// `~/.cargo/registry/src/.../your-crate-1.0.0/` has no `.git` directory and `$HOME` may not be
+/// set at all in some build environments, so this is the last line of defense against walking all
+/// the way to the filesystem root.
+const MAX_WALK_DEPTH: usize = 64;
+
+/// Search `start` and its ancestors for a `.python-version` file, returning it together with how
+/// many directories up from `start` it was found.
+///
+/// Stops walking, without finding a file, as soon as any of the following is true:
+/// - the current directory contains a `.git` entry (a repo boundary)
+/// - the current directory is `$HOME`
+/// - [`MAX_WALK_DEPTH`] directories have been walked
+///
+/// This keeps an unrelated `.python-version` further up the directory tree (a pyenv-pinned home
+/// directory, a sibling project in a monorepo, or just the filesystem root) from being picked up.
+fn find_version_file(start: &Path) -> Option<(PathBuf, usize)> {
+    let home = env::var_os("HOME").map(PathBuf::from);
+    let mut dir = Some(start);
+    let mut depth = 0;
+
+    while let Some(d) = dir {
+        let candidate = d.join(".python-version");
+        if candidate.is_file() {
+            return Some((candidate, depth));
+        }
+
+        if should_stop_walk(d, home.as_deref(), depth) {
+            return None;
+        }
+
+        dir = d.parent();
+        depth += 1;
+    }
+
+    None
+}
+
+/// Whether `find_version_file` should stop walking upward from `dir` without finding a file.
+fn should_stop_walk(dir: &Path, home: Option<&Path>, depth: usize) -> bool {
+    dir.join(".git").exists() || home == Some(dir) || depth >= MAX_WALK_DEPTH
+}
+
+/// Parse the first non-comment, non-blank line of a `.python-version` file.
+///
+/// Tolerates `pypy@3.10`-style implementation prefixes by ignoring everything up to and including
+/// the last `@`.
+fn parse(contents: &str) -> Option<VersionRequest> {
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let version = line.rsplit('@').next().unwrap_or(line);
+        return VersionRequest::exact(version);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_bare_version() {
+        let req = parse("3.12\n").unwrap();
+        assert!(matches!(req, VersionRequest::Exact(v) if v.major == 3 && v.minor == 12));
+    }
+
+    #[test]
+    fn parse_implementation_prefix() {
+        let req = parse("pypy@3.10\n").unwrap();
+        assert!(matches!(req, VersionRequest::Exact(v) if v.major == 3 && v.minor == 10));
+    }
+
+    #[test]
+    fn skips_comments_and_blank_lines() {
+        let req = parse("# pin our Python version\n\n3.9\n").unwrap();
+        assert!(matches!(req, VersionRequest::Exact(v) if v.major == 3 && v.minor == 9));
+    }
+
+    #[test]
+    fn empty_file_has_no_request() {
+        assert!(parse("# nothing pinned here\n").is_none());
+    }
+
+    #[test]
+    fn find_version_file_stops_at_repo_boundary() {
+        let root = env::temp_dir().join("xshell-venv-test-find-version-file");
+        let repo = root.join("repo");
+        let nested = repo.join("nested");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::write(root.join(".python-version"), "3.9\n").unwrap();
+
+        assert_eq!(find_version_file(&nested), None);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn find_version_file_finds_file_within_repo_boundary() {
+        let root = env::temp_dir().join("xshell-venv-test-find-version-file-within");
+        let repo = root.join("repo");
+        let nested = repo.join("nested");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::write(repo.join(".python-version"), "3.9\n").unwrap();
+
+        let (found, depth) = find_version_file(&nested).unwrap();
+        assert_eq!(found, repo.join(".python-version"));
+        assert_eq!(depth, 1);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn find_version_file_stops_at_depth_cap_with_no_git_boundary() {
+        // Simulates a crate extracted from a registry (e.g. ~/.cargo/registry/src/...), which has
+        // no `.git` directory anywhere above it.
+        let root = env::temp_dir().join("xshell-venv-test-find-version-file-no-git");
+        let mut leaf = root.clone();
+        for i in 0..(MAX_WALK_DEPTH + 2) {
+            leaf = leaf.join(format!("d{i}"));
+        }
+        std::fs::create_dir_all(&leaf).unwrap();
+        std::fs::write(root.join(".python-version"), "3.9\n").unwrap();
+
+        assert_eq!(find_version_file(&leaf), None);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn should_stop_walk_at_home() {
+        let home = Path::new("/home/user");
+        assert!(should_stop_walk(home, Some(home), 0));
+        assert!(!should_stop_walk(
+            Path::new("/home/user/project"),
+            Some(home),
+            0
+        ));
+        assert!(!should_stop_walk(home, None, 0));
+    }
+
+    #[test]
+    fn should_stop_walk_at_depth_cap() {
+        assert!(should_stop_walk(
+            Path::new("/some/dir"),
+            None,
+            MAX_WALK_DEPTH
+        ));
+        assert!(!should_stop_walk(
+            Path::new("/some/dir"),
+            None,
+            MAX_WALK_DEPTH - 1
+        ));
+    }
+}
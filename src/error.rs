@@ -6,6 +6,7 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 /// An error returned by an `xshell` operation.
 pub enum Error {
     PythonNotDetected(&'static str),
+    VersionNotFound(String),
     Xshell(xshell::Error),
 }
 
@@ -13,6 +14,7 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::PythonNotDetected(s) => write!(f, "{}", s),
+            Error::VersionNotFound(s) => write!(f, "{}", s),
             Error::Xshell(e) => write!(f, "{}", e),
         }
     }
@@ -30,6 +32,12 @@ impl From<&'static str> for Error {
     }
 }
 
+impl From<String> for Error {
+    fn from(msg: String) -> Error {
+        Error::VersionNotFound(msg)
+    }
+}
+
 impl fmt::Debug for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(self, f)
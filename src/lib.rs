@@ -18,17 +18,25 @@
 //! # }
 //! ```
 
+mod backend;
+mod discovery;
 mod error;
+mod pyvenv;
+mod version;
 
+use std::collections::hash_map::DefaultHasher;
 use std::env;
 use std::fs::File;
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 
 use fd_lock::RwLock;
 use xshell::PushEnv;
 pub use xshell::Shell;
 
+pub use backend::Backend;
 pub use error::{Error, Result};
+pub use version::{PyVersion, VersionRequest};
 
 #[cfg(not(windows))]
 static MICROVENV_CODE: &str = include_str!("microvenv.py");
@@ -66,7 +74,9 @@ macro_rules! cmd {
 /// ```
 pub struct VirtualEnv<'a> {
     shell: &'a Shell,
+    venv_dir: PathBuf,
     _env: Vec<PushEnv<'a>>,
+    backend: Backend,
 }
 
 fn guess_python(sh: &Shell) -> Result<&'static str, Error> {
@@ -96,8 +106,39 @@ fn guess_python(sh: &Shell) -> Result<&'static str, Error> {
     Err("couldn't find Python 3 in $PATH".into())
 }
 
+/// Like [`guess_python`], but only accepts an interpreter satisfying `req`.
+///
+/// Tries versioned binary names first (`python3.11`, `python3.11.exe`), then falls back to
+/// probing `python3`/`python` and comparing their reported version.
+fn guess_python_versioned(sh: &Shell, req: &VersionRequest) -> Result<String, Error> {
+    for name in req.candidate_names() {
+        if let Ok(output) = xshell::cmd!(sh, "{name} --version").read() {
+            if let Some(found) = PyVersion::parse_version_output(&output) {
+                if req.matches(found) {
+                    return Ok(name);
+                }
+            }
+        }
+    }
+
+    for name in ["python3", "python"] {
+        #[cfg(windows)]
+        let name = format!("{name}.exe");
+
+        if let Ok(output) = xshell::cmd!(sh, "{name} --version").read() {
+            if let Some(found) = PyVersion::parse_version_output(&output) {
+                if req.matches(found) {
+                    return Ok(name.to_string());
+                }
+            }
+        }
+    }
+
+    Err(format!("couldn't find a Python interpreter satisfying {req} in $PATH").into())
+}
+
 #[cfg(not(windows))]
-fn create_venv(sh: &Shell, path: &Path) -> Result<(), Error> {
+fn create_venv(sh: &Shell, path: &Path, python: &str, backend: Backend) -> Result<(), Error> {
     // First create a lock file, so that multiple runs cannot overlap.
     let lock_path = path.join("xshell-venv.lock");
     sh.create_dir(path)?;
@@ -106,11 +147,18 @@ fn create_venv(sh: &Shell, path: &Path) -> Result<(), Error> {
 
     let pybin = path.join("bin").join("python");
     if !pybin.exists() {
-        let python = guess_python(sh)?;
-        xshell::cmd!(sh, "{python} -c {MICROVENV_CODE} {path}").run()?;
-        // microvenv skips pip.
-        // `ensurepip` exists in the Python distribution, so let's use it.
-        xshell::cmd!(sh, "{path}/bin/python -m ensurepip").run()?;
+        match backend {
+            Backend::Uv => {
+                // `--seed` installs pip, as `ensurepip`/`venv` do for the pip backend.
+                xshell::cmd!(sh, "uv venv --seed --python {python} {path}").run()?;
+            }
+            Backend::Pip => {
+                xshell::cmd!(sh, "{python} -c {MICROVENV_CODE} {path}").run()?;
+                // microvenv skips pip.
+                // `ensurepip` exists in the Python distribution, so let's use it.
+                xshell::cmd!(sh, "{path}/bin/python -m ensurepip").run()?;
+            }
+        }
     }
 
     // Work is done. Drop the lock.
@@ -122,7 +170,7 @@ fn create_venv(sh: &Shell, path: &Path) -> Result<(), Error> {
 // microvenv.py doesn't work on Windows.
 // We fallback to simply using Python's `venv` module again.
 #[cfg(windows)]
-fn create_venv(sh: &Shell, path: &Path) -> Result<(), Error> {
+fn create_venv(sh: &Shell, path: &Path, python: &str, backend: Backend) -> Result<(), Error> {
     // First create a lock file, so that multiple runs cannot overlap.
     let lock_path = path.join("xshell-venv.lock");
     sh.create_dir(path)?;
@@ -131,8 +179,15 @@ fn create_venv(sh: &Shell, path: &Path) -> Result<(), Error> {
 
     let pybin = path.join("bin").join("python");
     if !pybin.exists() {
-        let python = guess_python(sh)?;
-        xshell::cmd!(sh, "{python} -m venv {path}").run()?;
+        match backend {
+            Backend::Uv => {
+                // `--seed` installs pip, as `ensurepip`/`venv` do for the pip backend.
+                xshell::cmd!(sh, "uv venv --seed --python {python} {path}").run()?;
+            }
+            Backend::Pip => {
+                xshell::cmd!(sh, "{python} -m venv {path}").run()?;
+            }
+        }
     }
 
     // Work is done. Drop the lock.
@@ -204,6 +259,15 @@ impl<'a> VirtualEnv<'a> {
     ///
     /// If none of these are set it will use the system's temporary directory, e.g. `/tmp`.
     ///
+    /// If a `.python-version` file is found by walking up from `CARGO_MANIFEST_DIR` or the
+    /// current directory, the environment is pinned to the version it requests instead of
+    /// whatever `python3`/`python` happens to resolve to first. The walk is bounded (repo
+    /// boundary, `$HOME`, and a hard depth cap — see
+    /// [`VirtualEnvBuilder::discover_python_version`]) so an unrelated file further up the
+    /// directory tree can't surprise you; pass `VirtualEnv::builder(sh,
+    /// name).discover_python_version(false)` to disable this and always use whatever
+    /// `python3`/`python` resolves to first.
+    ///
     /// ## Example
     ///
     /// ```
@@ -216,9 +280,54 @@ impl<'a> VirtualEnv<'a> {
     /// # }
     /// ```
     pub fn new(shell: &'a Shell, name: &str) -> Result<VirtualEnv<'a>, Error> {
-        let venv_dir = find_directory(name);
+        VirtualEnv::builder(shell, name).build()
+    }
 
-        Self::with_path(shell, &venv_dir)
+    /// Create a Python virtual environment with the given name, pinned to a specific
+    /// `major.minor` version.
+    ///
+    /// This searches for a versioned interpreter (`python3.11`, `python3.11.exe`), falling back
+    /// to probing `python3`/`python` and comparing their reported version. Returns an error if no
+    /// interpreter on `$PATH` satisfies the request.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// # use xshell_venv::{Shell, VirtualEnv};
+    /// # fn main() -> xshell_venv::Result<()> {
+    /// let sh = Shell::new()?;
+    /// let venv = VirtualEnv::with_version(&sh, "py3", "3.11")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_version(
+        shell: &'a Shell,
+        name: &str,
+        version: &str,
+    ) -> Result<VirtualEnv<'a>, Error> {
+        let req = VersionRequest::exact(version).ok_or_else(|| {
+            format!("'{version}' is not a valid Python version, expected e.g. '3.11'")
+        })?;
+
+        VirtualEnv::builder(shell, name).python_version(req).build()
+    }
+
+    /// Start building a [`VirtualEnv`] with more control than [`VirtualEnv::new`] offers, such as
+    /// pinning a minimum Python version.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// # use xshell_venv::{Shell, VirtualEnv, VersionRequest};
+    /// # fn main() -> xshell_venv::Result<()> {
+    /// let sh = Shell::new()?;
+    /// let req = VersionRequest::at_least("3.10").unwrap();
+    /// let venv = VirtualEnv::builder(&sh, "py3").python_version(req).build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder(shell: &'a Shell, name: &str) -> VirtualEnvBuilder<'a> {
+        VirtualEnvBuilder::new(shell, name)
     }
 
     /// Create a Python virtual environment in the given path.
@@ -242,7 +351,18 @@ impl<'a> VirtualEnv<'a> {
     /// # }
     /// ```
     pub fn with_path(shell: &'a Shell, venv_dir: &Path) -> Result<VirtualEnv<'a>, Error> {
-        create_venv(shell, venv_dir)?;
+        let python = guess_python(shell)?;
+        let backend = Backend::detect(shell);
+        Self::with_path_and_python(shell, venv_dir, python, backend)
+    }
+
+    fn with_path_and_python(
+        shell: &'a Shell,
+        venv_dir: &Path,
+        python: &str,
+        backend: Backend,
+    ) -> Result<VirtualEnv<'a>, Error> {
+        create_venv(shell, venv_dir, python, backend)?;
 
         let path = env::var("PATH").unwrap_or_else(|_| "/bin:/usr/bin".to_string());
         let path = format!("{}/bin:{}", venv_dir.display(), path);
@@ -251,7 +371,12 @@ impl<'a> VirtualEnv<'a> {
         env.push(shell.push_env("VIRTUAL_ENV", format!("{}", venv_dir.display())));
         env.push(shell.push_env("PATH", path));
 
-        Ok(VirtualEnv { shell, _env: env })
+        Ok(VirtualEnv {
+            shell,
+            venv_dir: venv_dir.to_path_buf(),
+            _env: env,
+            backend,
+        })
     }
 
     /// Install a Python package in this virtual environment.
@@ -260,6 +385,9 @@ impl<'a> VirtualEnv<'a> {
     /// including specifying the version (`$name==1.0.0`)
     /// or repositories (`git+https://github.com/$name/$repo@branch#egg=$name`).
     ///
+    /// Uses `uv pip install` instead of `pip3 install` if this environment's [`Backend`] is
+    /// [`Backend::Uv`].
+    ///
     /// ## Example
     ///
     /// ```rust,ignore
@@ -275,7 +403,10 @@ impl<'a> VirtualEnv<'a> {
     /// # }
     /// ```
     pub fn pip_install(&self, package: &str) -> Result<()> {
-        cmd!(self.shell, "pip3 install {package}").run()?;
+        match self.backend {
+            Backend::Uv => cmd!(self.shell, "uv pip install {package}").run()?,
+            Backend::Pip => cmd!(self.shell, "pip3 install {package}").run()?,
+        };
         Ok(())
     }
 
@@ -285,6 +416,9 @@ impl<'a> VirtualEnv<'a> {
     /// including specifying the version (`$name==1.0.0`)
     /// or repositories (`git+https://github.com/$name/$repo@branch#egg=$name`).
     ///
+    /// Uses `uv pip install --upgrade` instead of `pip3 install --upgrade` if this environment's
+    /// [`Backend`] is [`Backend::Uv`].
+    ///
     /// ## Example
     ///
     /// ```rust,ignore
@@ -304,7 +438,87 @@ impl<'a> VirtualEnv<'a> {
     /// # }
     /// ```
     pub fn pip_upgrade(&self, package: &str) -> Result<()> {
-        cmd!(self.shell, "pip3 install --upgrade {package}").run()?;
+        match self.backend {
+            Backend::Uv => cmd!(self.shell, "uv pip install --upgrade {package}").run()?,
+            Backend::Pip => cmd!(self.shell, "pip3 install --upgrade {package}").run()?,
+        };
+        Ok(())
+    }
+
+    /// Install packages from a `requirements.txt`-style file.
+    ///
+    /// Uses `uv pip install -r {path}` instead of `pip3 install -r {path}` if this environment's
+    /// [`Backend`] is [`Backend::Uv`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use xshell_venv::{Shell, VirtualEnv};
+    /// # fn main() -> xshell_venv::Result<()> {
+    /// let sh = Shell::new()?;
+    /// let venv = VirtualEnv::new(&sh, "py3")?;
+    ///
+    /// venv.pip_install_requirements("requirements.txt")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pip_install_requirements(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        match self.backend {
+            Backend::Uv => cmd!(self.shell, "uv pip install -r {path}").run()?,
+            Backend::Pip => cmd!(self.shell, "pip3 install -r {path}").run()?,
+        };
+        Ok(())
+    }
+
+    /// Return the output of `pip freeze` (or `uv pip freeze`), verbatim.
+    ///
+    /// Callers can diff this against an existing lockfile written by [`VirtualEnv::lock`] to
+    /// detect drift, or write it out themselves.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use xshell_venv::{Shell, VirtualEnv};
+    /// # fn main() -> xshell_venv::Result<()> {
+    /// let sh = Shell::new()?;
+    /// let venv = VirtualEnv::new(&sh, "py3")?;
+    ///
+    /// venv.pip_install("flake8")?;
+    /// let frozen = venv.freeze()?;
+    /// assert!(frozen.contains("flake8"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn freeze(&self) -> Result<String> {
+        let output = match self.backend {
+            Backend::Uv => cmd!(self.shell, "uv pip freeze").read()?,
+            Backend::Pip => cmd!(self.shell, "pip3 freeze").read()?,
+        };
+        Ok(output)
+    }
+
+    /// Write the output of [`VirtualEnv::freeze`] to `path`.
+    ///
+    /// This lets a build script materialize a pinned set of packages once, then reproduce it
+    /// deterministically on later builds via [`VirtualEnv::pip_install_requirements`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use xshell_venv::{Shell, VirtualEnv};
+    /// # fn main() -> xshell_venv::Result<()> {
+    /// let sh = Shell::new()?;
+    /// let venv = VirtualEnv::new(&sh, "py3")?;
+    ///
+    /// venv.pip_install("flake8")?;
+    /// venv.lock("requirements.lock")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lock(&self, path: impl AsRef<Path>) -> Result<()> {
+        let frozen = self.freeze()?;
+        self.shell.write_file(path, frozen)?;
         Ok(())
     }
 
@@ -353,6 +567,237 @@ impl<'a> VirtualEnv<'a> {
         let py = cmd!(self.shell, "python -m {module} {args...}");
         Ok(py.read()?)
     }
+
+    /// Run Python code with extra packages layered on top of this environment, without
+    /// installing them into it.
+    ///
+    /// The extra requirements are installed into a separate, ephemeral environment keyed by a
+    /// hash of `extra_requirements`, so it's created once and reused on later calls with the same
+    /// list. `PATH` and `PYTHONPATH` are set up so imports resolve from the ephemeral environment
+    /// first and fall through to this one; this environment's own installed packages are never
+    /// modified.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use xshell_venv::{Shell, VirtualEnv};
+    /// # fn main() -> xshell_venv::Result<()> {
+    /// let sh = Shell::new()?;
+    /// let venv = VirtualEnv::new(&sh, "py3")?;
+    ///
+    /// let output = venv.run_with(&["requests"], "import requests; print(requests.__version__)")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn run_with(&self, extra_requirements: &[&str], code: &str) -> Result<String> {
+        let overlay_dir = self.ephemeral_env_dir(extra_requirements);
+        let python = self.venv_dir.join("bin").join("python");
+        create_venv(
+            self.shell,
+            &overlay_dir,
+            &python.display().to_string(),
+            self.backend,
+        )?;
+
+        for package in extra_requirements {
+            pip_install_into(self.shell, &overlay_dir, self.backend, package)?;
+        }
+
+        let overlay_site_packages = pyvenv::site_packages_dir(
+            &overlay_dir,
+            pyvenv::PyvenvCfg::read(&overlay_dir).and_then(|cfg| cfg.version()),
+        );
+        let base_site_packages = pyvenv::site_packages_dir(
+            &self.venv_dir,
+            pyvenv::PyvenvCfg::read(&self.venv_dir).and_then(|cfg| cfg.version()),
+        );
+
+        let path = env::var("PATH").unwrap_or_else(|_| "/bin:/usr/bin".to_string());
+        let path = format!("{}/bin:{}", overlay_dir.display(), path);
+        let pythonpath = format!(
+            "{}:{}",
+            overlay_site_packages.display(),
+            base_site_packages.display()
+        );
+
+        let _path_env = self.shell.push_env("PATH", path);
+        let _pythonpath_env = self.shell.push_env("PYTHONPATH", pythonpath);
+
+        let py = cmd!(self.shell, "python");
+        Ok(py.stdin(code).read()?)
+    }
+
+    /// The directory for the ephemeral overlay environment for `extra_requirements`, keyed by a
+    /// hash of the (order-sensitive) requirements list so it can be cached and reused.
+    fn ephemeral_env_dir(&self, extra_requirements: &[&str]) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        extra_requirements.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        self.venv_dir.join("overlays").join(format!("{hash:016x}"))
+    }
+
+    /// The Python version this environment was created with.
+    ///
+    /// Parsed from the `version`/`version_info` key in the venv's `pyvenv.cfg`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use xshell_venv::{Shell, VirtualEnv};
+    /// # fn main() -> xshell_venv::Result<()> {
+    /// let sh = Shell::new()?;
+    /// let venv = VirtualEnv::new(&sh, "py3")?;
+    ///
+    /// let version = venv.python_version();
+    /// assert!(version.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn python_version(&self) -> Option<PyVersion> {
+        pyvenv::PyvenvCfg::read(&self.venv_dir)?.version()
+    }
+
+    /// The `site-packages` directory this environment installs packages into.
+    ///
+    /// Derived from the venv's Python version as `lib/pythonX.Y/site-packages`
+    /// (`Lib\site-packages` on Windows).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use xshell_venv::{Shell, VirtualEnv};
+    /// # fn main() -> xshell_venv::Result<()> {
+    /// let sh = Shell::new()?;
+    /// let venv = VirtualEnv::new(&sh, "py3")?;
+    ///
+    /// let site_packages = venv.site_packages();
+    /// assert!(site_packages.ends_with("site-packages"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn site_packages(&self) -> PathBuf {
+        pyvenv::site_packages_dir(&self.venv_dir, self.python_version())
+    }
+
+    /// The base Python installation this environment was created from.
+    ///
+    /// Read from the `home` key (the directory containing the base interpreter's executable) in
+    /// the venv's `pyvenv.cfg`, falling back to `base-prefix` if `home` isn't present.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use xshell_venv::{Shell, VirtualEnv};
+    /// # fn main() -> xshell_venv::Result<()> {
+    /// let sh = Shell::new()?;
+    /// let venv = VirtualEnv::new(&sh, "py3")?;
+    ///
+    /// println!("{}", venv.base_prefix().unwrap());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn base_prefix(&self) -> Option<PathBuf> {
+        let cfg = pyvenv::PyvenvCfg::read(&self.venv_dir)?;
+        cfg.home().or_else(|| cfg.base_prefix()).map(PathBuf::from)
+    }
+}
+
+/// Install `package` into the venv at `venv_dir`, without relying on it being activated in `sh`.
+fn pip_install_into(sh: &Shell, venv_dir: &Path, backend: Backend, package: &str) -> Result<()> {
+    match backend {
+        Backend::Uv => {
+            let python = venv_dir.join("bin").join("python");
+            xshell::cmd!(sh, "uv pip install --python {python} {package}").run()?;
+        }
+        Backend::Pip => {
+            let pip = venv_dir.join("bin").join("pip3");
+            xshell::cmd!(sh, "{pip} install {package}").run()?;
+        }
+    }
+    Ok(())
+}
+
+/// Builder for [`VirtualEnv`], for cases where the defaults in [`VirtualEnv::new`] aren't enough.
+///
+/// Created with [`VirtualEnv::builder`].
+pub struct VirtualEnvBuilder<'a> {
+    shell: &'a Shell,
+    name: String,
+    path: Option<PathBuf>,
+    version: Option<VersionRequest>,
+    backend: Option<Backend>,
+    discover_version_file: bool,
+}
+
+impl<'a> VirtualEnvBuilder<'a> {
+    fn new(shell: &'a Shell, name: &str) -> Self {
+        VirtualEnvBuilder {
+            shell,
+            name: name.to_string(),
+            path: None,
+            version: None,
+            backend: None,
+            discover_version_file: true,
+        }
+    }
+
+    /// Use this directory instead of the one [`VirtualEnv::new`] would auto-detect.
+    pub fn path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Require a specific Python version when creating the environment.
+    ///
+    /// Takes priority over a discovered `.python-version` file; see
+    /// [`VirtualEnvBuilder::discover_python_version`].
+    pub fn python_version(mut self, version: VersionRequest) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Whether to pick up a `.python-version` file when [`VirtualEnvBuilder::python_version`]
+    /// wasn't called explicitly. On by default.
+    ///
+    /// The file is found by walking up from `CARGO_MANIFEST_DIR` or the current directory, and
+    /// parsed the same way `pyenv`/`uv`/etc. do. The walk stops well short of the filesystem root
+    /// (see `discovery::find_version_file`'s doc comment for the exact bounds), so an unrelated
+    /// `.python-version` from outside the project can't silently pin an unexpected version. Pass
+    /// `false` to disable discovery entirely and always use whatever `python3`/`python` resolves
+    /// to first on `$PATH`.
+    pub fn discover_python_version(mut self, enabled: bool) -> Self {
+        self.discover_version_file = enabled;
+        self
+    }
+
+    /// Force a specific backend instead of auto-detecting whether `uv` is on `$PATH`.
+    pub fn backend(mut self, backend: Backend) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+
+    /// Create (or reuse) the virtual environment with the configured options.
+    pub fn build(self) -> Result<VirtualEnv<'a>> {
+        let venv_dir = self.path.unwrap_or_else(|| find_directory(&self.name));
+
+        let requested_version = self.version.or_else(|| {
+            if self.discover_version_file {
+                discovery::discover()
+            } else {
+                None
+            }
+        });
+
+        let python = match requested_version {
+            Some(req) => guess_python_versioned(self.shell, &req)?,
+            None => guess_python(self.shell)?.to_string(),
+        };
+
+        let backend = self.backend.unwrap_or_else(|| Backend::detect(self.shell));
+
+        VirtualEnv::with_path_and_python(self.shell, &venv_dir, &python, backend)
+    }
 }
 
 #[cfg(all(unix, test))]
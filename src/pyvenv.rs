@@ -0,0 +1,155 @@
+//! Parsing of a virtual environment's `pyvenv.cfg` file.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::PyVersion;
+
+/// The parsed contents of a venv's `pyvenv.cfg` file: simple `key = value` lines, ignoring
+/// comments and blank lines.
+#[derive(Debug)]
+pub(crate) struct PyvenvCfg {
+    entries: HashMap<String, String>,
+}
+
+impl PyvenvCfg {
+    /// Read and parse `{venv_dir}/pyvenv.cfg`. Returns `None` if it doesn't exist.
+    pub(crate) fn read(venv_dir: &Path) -> Option<PyvenvCfg> {
+        let contents = std::fs::read_to_string(venv_dir.join("pyvenv.cfg")).ok()?;
+        Some(PyvenvCfg::parse(&contents))
+    }
+
+    /// Parse the `key = value` lines of a `pyvenv.cfg` file's contents, ignoring comments and
+    /// blank lines.
+    fn parse(contents: &str) -> PyvenvCfg {
+        let mut entries = HashMap::new();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                entries.insert(key.trim().to_string(), value.trim().to_string());
+            }
+        }
+
+        PyvenvCfg { entries }
+    }
+
+    /// The interpreter version, from the `version_info` or `version` key.
+    pub(crate) fn version(&self) -> Option<PyVersion> {
+        self.entries
+            .get("version_info")
+            .or_else(|| self.entries.get("version"))
+            .and_then(|v| PyVersion::parse(v))
+    }
+
+    /// The `home` key: the directory containing the base interpreter's executable.
+    pub(crate) fn home(&self) -> Option<&str> {
+        self.entries.get("home").map(String::as_str)
+    }
+
+    /// The `base-prefix` key, if present.
+    pub(crate) fn base_prefix(&self) -> Option<&str> {
+        self.entries.get("base-prefix").map(String::as_str)
+    }
+}
+
+/// Derive the platform-correct `site-packages` directory for a venv, given its parsed version.
+pub(crate) fn site_packages_dir(venv_dir: &Path, version: Option<PyVersion>) -> PathBuf {
+    if cfg!(windows) {
+        windows_site_packages_dir(venv_dir)
+    } else {
+        unix_site_packages_dir(venv_dir, version)
+    }
+}
+
+fn windows_site_packages_dir(venv_dir: &Path) -> PathBuf {
+    venv_dir.join("Lib").join("site-packages")
+}
+
+fn unix_site_packages_dir(venv_dir: &Path, version: Option<PyVersion>) -> PathBuf {
+    match version {
+        Some(v) => venv_dir
+            .join("lib")
+            .join(format!("python{}.{}", v.major, v.minor))
+            .join("site-packages"),
+        None => venv_dir.join("lib").join("site-packages"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parses_key_value_pairs_ignoring_comments_and_blanks() {
+        let cfg = PyvenvCfg::parse(
+            "home = /usr/bin\n# a comment\n\nversion = 3.11.4\nbase-prefix = /usr\n",
+        );
+        assert_eq!(cfg.home(), Some("/usr/bin"));
+        assert_eq!(cfg.base_prefix(), Some("/usr"));
+        assert_eq!(
+            cfg.version(),
+            Some(PyVersion {
+                major: 3,
+                minor: 11,
+                patch: 4
+            })
+        );
+    }
+
+    #[test]
+    fn prefers_version_info_over_version() {
+        let cfg = PyvenvCfg::parse("version_info = 3.12.1\nversion = 3.11.4\n");
+        assert_eq!(
+            cfg.version(),
+            Some(PyVersion {
+                major: 3,
+                minor: 12,
+                patch: 1
+            })
+        );
+    }
+
+    #[test]
+    fn missing_keys_return_none() {
+        let cfg = PyvenvCfg::parse("executable = /usr/bin/python3\n");
+        assert_eq!(cfg.version(), None);
+        assert_eq!(cfg.home(), None);
+        assert_eq!(cfg.base_prefix(), None);
+    }
+
+    #[test]
+    fn empty_contents_have_no_entries() {
+        let cfg = PyvenvCfg::parse("");
+        assert_eq!(cfg.version(), None);
+        assert_eq!(cfg.home(), None);
+        assert_eq!(cfg.base_prefix(), None);
+    }
+
+    #[test]
+    fn unix_site_packages_with_version() {
+        let dir = unix_site_packages_dir(
+            Path::new("/venv"),
+            Some(PyVersion {
+                major: 3,
+                minor: 11,
+                patch: 0,
+            }),
+        );
+        assert_eq!(dir, Path::new("/venv/lib/python3.11/site-packages"));
+    }
+
+    #[test]
+    fn unix_site_packages_without_version() {
+        let dir = unix_site_packages_dir(Path::new("/venv"), None);
+        assert_eq!(dir, Path::new("/venv/lib/site-packages"));
+    }
+
+    #[test]
+    fn windows_site_packages_ignores_version() {
+        let dir = windows_site_packages_dir(Path::new(r"C:\venv"));
+        assert_eq!(dir, Path::new(r"C:\venv").join("Lib").join("site-packages"));
+    }
+}
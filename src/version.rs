@@ -0,0 +1,179 @@
+//! Parsing and matching of Python version numbers.
+
+use std::fmt;
+
+/// A parsed Python version, e.g. `3.11.4`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PyVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl PyVersion {
+    /// Parse a bare version string like `"3.11"` or `"3.11.4"`.
+    pub fn parse(s: &str) -> Option<PyVersion> {
+        let mut parts = s.trim().splitn(3, '.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = parts.next().and_then(|p| p.parse().ok()).unwrap_or(0);
+        Some(PyVersion {
+            major,
+            minor,
+            patch,
+        })
+    }
+
+    /// Parse the `Python X.Y.Z` string that `python --version` prints.
+    pub(crate) fn parse_version_output(s: &str) -> Option<PyVersion> {
+        let s = s.trim().strip_prefix("Python ")?;
+        PyVersion::parse(s)
+    }
+}
+
+impl fmt::Display for PyVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// A request for a specific Python version, used by [`crate::VirtualEnv::with_version`] and
+/// [`crate::VirtualEnvBuilder::python_version`].
+#[derive(Debug, Clone, Copy)]
+pub enum VersionRequest {
+    /// Match this exact `major.minor`, ignoring the patch version.
+    Exact(PyVersion),
+    /// Match this version or any newer one.
+    AtLeast(PyVersion),
+}
+
+impl VersionRequest {
+    /// Parse an exact `major.minor[.patch]` request, e.g. `"3.11"`.
+    pub fn exact(version: &str) -> Option<VersionRequest> {
+        PyVersion::parse(version).map(VersionRequest::Exact)
+    }
+
+    /// Parse a minimum `major.minor[.patch]` request, e.g. `"3.11"`.
+    pub fn at_least(version: &str) -> Option<VersionRequest> {
+        PyVersion::parse(version).map(VersionRequest::AtLeast)
+    }
+
+    pub(crate) fn matches(&self, found: PyVersion) -> bool {
+        match self {
+            VersionRequest::Exact(want) => found.major == want.major && found.minor == want.minor,
+            VersionRequest::AtLeast(want) => found >= *want,
+        }
+    }
+
+    /// Versioned interpreter binary names to try before falling back to probing
+    /// `python3`/`python`, e.g. `python3.11` / `python3.11.exe`.
+    ///
+    /// For [`VersionRequest::AtLeast`] this can't know what's actually installed, so it probes a
+    /// range of minors from the requested one up through [`MAX_MINOR_PROBE`] above it, in order, before
+    /// falling back to the slower `python3`/`python` probe.
+    pub(crate) fn candidate_names(&self) -> Vec<String> {
+        match self {
+            VersionRequest::Exact(v) => vec![binary_name(v.major, v.minor)],
+            VersionRequest::AtLeast(v) => (v.minor..=v.minor + MAX_MINOR_PROBE)
+                .map(|minor| binary_name(v.major, minor))
+                .collect(),
+        }
+    }
+}
+
+/// How many minors above an `AtLeast` request's own minor to probe for a versioned binary, e.g.
+/// `python3.12`..`python3.31` when asked for `at_least("3.11")`. Comfortably ahead of any CPython
+/// 3.x minor released so far, without probing an unbounded range.
+const MAX_MINOR_PROBE: u32 = 20;
+
+fn binary_name(major: u32, minor: u32) -> String {
+    #[cfg(windows)]
+    {
+        format!("python{major}.{minor}.exe")
+    }
+    #[cfg(not(windows))]
+    {
+        format!("python{major}.{minor}")
+    }
+}
+
+impl fmt::Display for VersionRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionRequest::Exact(v) => write!(f, "=={}.{}", v.major, v.minor),
+            VersionRequest::AtLeast(v) => write!(f, ">={v}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_versions() {
+        assert_eq!(
+            PyVersion::parse("3.11"),
+            Some(PyVersion {
+                major: 3,
+                minor: 11,
+                patch: 0
+            })
+        );
+        assert_eq!(
+            PyVersion::parse("3.11.4"),
+            Some(PyVersion {
+                major: 3,
+                minor: 11,
+                patch: 4
+            })
+        );
+        assert_eq!(PyVersion::parse("not-a-version"), None);
+    }
+
+    #[test]
+    fn parse_version_output() {
+        assert_eq!(
+            PyVersion::parse_version_output("Python 3.11.4\n"),
+            Some(PyVersion {
+                major: 3,
+                minor: 11,
+                patch: 4
+            })
+        );
+        assert_eq!(PyVersion::parse_version_output("not python"), None);
+    }
+
+    #[test]
+    fn version_request_matches() {
+        let v = |s| PyVersion::parse(s).unwrap();
+
+        let exact = VersionRequest::exact("3.11").unwrap();
+        assert!(exact.matches(v("3.11.9")));
+        assert!(!exact.matches(v("3.12.0")));
+
+        let at_least = VersionRequest::at_least("3.11").unwrap();
+        assert!(at_least.matches(v("3.11.0")));
+        assert!(at_least.matches(v("3.12.0")));
+        assert!(!at_least.matches(v("3.10.9")));
+    }
+
+    #[test]
+    fn at_least_candidate_names_probe_newer_minors() {
+        let at_least = VersionRequest::at_least("3.11").unwrap();
+        let names = at_least.candidate_names();
+
+        assert_eq!(
+            names.first().map(String::as_str),
+            Some(binary_name(3, 11)).as_deref()
+        );
+        assert!(names.contains(&binary_name(3, 12)));
+        assert_eq!(names.len() as u32, MAX_MINOR_PROBE + 1);
+    }
+
+    #[test]
+    fn exact_candidate_names_is_just_the_requested_minor() {
+        let exact = VersionRequest::exact("3.11").unwrap();
+        assert_eq!(exact.candidate_names(), vec![binary_name(3, 11)]);
+    }
+}